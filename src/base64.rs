@@ -1,13 +1,149 @@
 use std::string::FromUtf8Error;
 
-/// The Base64 alphabet, used for encoding and decoding Base64 strings.
+/// The standard Base64 alphabet (`+` and `/` as the 62nd/63rd symbols).
 const BASE64_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
 
+/// The URL- and filename-safe Base64 alphabet (`-` and `_` in place of `+` and `/`).
+/// read more: https://datatracker.ietf.org/doc/html/rfc4648#section-5
+const BASE64_URL_SAFE_ALPHABET: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Selects which Base64 alphabet a [`Base64`] instance encodes and decodes with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterSet {
+    /// The standard alphabet (`+`, `/`), as defined in RFC 4648 section 4.
+    Standard,
+    /// The URL- and filename-safe alphabet (`-`, `_`), as defined in RFC 4648 section 5.
+    UrlSafe,
+}
+
+impl CharacterSet {
+    fn alphabet(&self) -> &'static str {
+        match self {
+            CharacterSet::Standard => BASE64_ALPHABET,
+            CharacterSet::UrlSafe => BASE64_URL_SAFE_ALPHABET,
+        }
+    }
+}
+
+/// The line separator used when wrapping encoded output, as configured via [`Config::wrap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Newline {
+    /// A single line feed (`\n`), as used on Unix.
+    LF,
+    /// A carriage return followed by a line feed (`\r\n`), as used by MIME/PEM and Windows.
+    CRLF,
+}
+
+impl Newline {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Newline::LF => "\n",
+            Newline::CRLF => "\r\n",
+        }
+    }
+}
+
+/// Builder for configuring a [`Base64`] instance.
+///
+/// # Example
+///
+/// ```
+/// let base64 = Base64::with_config(
+///     Config::new().charset(CharacterSet::UrlSafe).wrap(76, Newline::CRLF),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    charset: CharacterSet,
+    wrap: Option<(usize, Newline)>,
+    strict: bool,
+}
+
+impl Config {
+    /// Creates a new `Config` with the standard alphabet, no line wrapping, and lenient
+    /// decoding.
+    pub fn new() -> Self {
+        Self {
+            charset: CharacterSet::Standard,
+            wrap: None,
+            strict: false,
+        }
+    }
+
+    /// Sets the [`CharacterSet`] used for encoding and decoding.
+    pub fn charset(mut self, charset: CharacterSet) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Wraps encoded output at `width` characters, separated by `newline`.
+    ///
+    /// PEM/MIME typically use a width of 64 or 76. Decoding tolerates both `\n` and `\r\n`
+    /// regardless of this setting.
+    pub fn wrap(mut self, width: usize, newline: Newline) -> Self {
+        self.wrap = Some((width, newline));
+        self
+    }
+
+    /// Enables strict decoding: only canonical Base64 (no stray trailing symbol, padding only
+    /// on the final quad) is accepted. See [`Base64::strict`] for details.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the length of the Base64-encoded (and padded) output for an input of `input_len`
+/// bytes. Useful for preallocating an output buffer ahead of time.
+pub fn encoded_length(input_len: usize) -> usize {
+    ((input_len + 2) / 3) * 4
+}
+
+/// Estimates the number of decoded bytes a Base64-encoded `input` will produce, from its quad
+/// count minus any padding. Newline characters inserted by line wrapping are ignored. Useful for
+/// preallocating an output buffer ahead of time; truncated or malformed input may make this an
+/// underestimate, which is safe since the output buffer still grows as needed.
+pub fn decoded_length(input: &str) -> usize {
+    let significant_chars = input.chars().filter(|&c| c != '\n' && c != '\r').count();
+    let padding_count = input.chars().filter(|&c| c == '=').count();
+
+    ((significant_chars / 4) * 3).saturating_sub(padding_count)
+}
+
+/// Builds the forward (6-bit value -> symbol) and reverse (byte -> 6-bit value) lookup tables
+/// for `alphabet`, so encoding/decoding a character is a constant-time array index rather than
+/// an O(n) scan of the alphabet string.
+fn build_tables(alphabet: &'static str) -> ([u8; 64], [i8; 256]) {
+    let mut forward = [0u8; 64];
+    let mut reverse = [-1i8; 256];
+
+    for (idx, byte) in alphabet.bytes().enumerate() {
+        forward[idx] = byte;
+        reverse[byte as usize] = idx as i8;
+    }
+
+    (forward, reverse)
+}
+
 /// The `Base64` struct provides methods for encoding and decoding strings in Base64 format.
-pub struct Base64;
+pub struct Base64 {
+    /// Maps a 6-bit value to its Base64 symbol.
+    forward: [u8; 64],
+    /// Maps a byte to its 6-bit value, or `-1` if the byte isn't part of the alphabet.
+    reverse: [i8; 256],
+    wrap: Option<(usize, Newline)>,
+    strict: bool,
+}
 
 impl Base64 {
-    /// Creates a new `Base64` encoder/decoder instance.
+    /// Creates a new `Base64` encoder/decoder instance using the standard alphabet.
     ///
     /// # Example
     ///
@@ -15,7 +151,55 @@ impl Base64 {
     /// let base64 = Base64::new();
     /// ```
     pub fn new() -> Self {
-        Self {}
+        Self::with_config(Config::new())
+    }
+
+    /// Creates a new `Base64` encoder/decoder instance using the given [`CharacterSet`].
+    ///
+    /// Use `CharacterSet::UrlSafe` to encode and decode Base64 that is safe to embed in URLs
+    /// or filenames, without needing to manually substitute `+`/`/` for `-`/`_`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let base64 = Base64::with_charset(CharacterSet::UrlSafe);
+    /// ```
+    pub fn with_charset(charset: CharacterSet) -> Self {
+        Self::with_config(Config::new().charset(charset))
+    }
+
+    /// Creates a new `Base64` encoder/decoder instance with strict decoding enabled.
+    ///
+    /// In strict mode, `decode`/`decode_bytes` reject anything but canonical Base64: a stray
+    /// trailing symbol (non-padding symbol count mod 4 equal to 1), or a padding character that
+    /// appears anywhere other than the final quad, returns `Base64Error::InvalidLength` instead
+    /// of silently producing the wrong output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let base64 = Base64::strict();
+    /// ```
+    pub fn strict() -> Self {
+        Self::with_config(Config::new().strict())
+    }
+
+    /// Creates a new `Base64` encoder/decoder instance from a [`Config`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let base64 = Base64::with_config(Config::new().wrap(76, Newline::CRLF));
+    /// ```
+    pub fn with_config(config: Config) -> Self {
+        let (forward, reverse) = build_tables(config.charset.alphabet());
+
+        Self {
+            forward,
+            reverse,
+            wrap: config.wrap,
+            strict: config.strict,
+        }
     }
 
     /// Encodes the given input string into a Base64-encoded string.
@@ -41,30 +225,58 @@ impl Base64 {
     /// assert_eq!(encoded, "SGVsbG8=");
     /// ```
     pub fn encode(&self, input: &str) -> String {
-        let bytes = input.as_bytes();
-
-        bytes
-            .chunks(3)
-            .map(|chunk| {
-                // Create a 24-bit buffer
-                let mut buf: u32 = 0;
-                for (i, &byte) in chunk.iter().enumerate() {
-                    buf |= (byte as u32) << (16 - i * 8);
+        self.encode_bytes(input.as_bytes())
+    }
+
+    /// Encodes the given byte slice into a Base64-encoded string.
+    ///
+    /// Unlike [`Base64::encode`], this accepts arbitrary binary data (images, keys,
+    /// compressed blobs) rather than requiring a UTF-8 string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let base64 = Base64::new();
+    /// let encoded = base64.encode_bytes(b"Hello");
+    /// assert_eq!(encoded, "SGVsbG8=");
+    /// ```
+    pub fn encode_bytes(&self, input: &[u8]) -> String {
+        let mut unwrapped = String::with_capacity(encoded_length(input.len()));
+
+        for chunk in input.chunks(3) {
+            // Create a 24-bit buffer
+            let mut buf: u32 = 0;
+            for (i, &byte) in chunk.iter().enumerate() {
+                buf |= (byte as u32) << (16 - i * 8);
+            }
+
+            // Encode the 24-bit buffer into 4 Base64 characters
+            for i in 0..4 {
+                if i < chunk.len() + 1 {
+                    let idx = (buf >> (18 - i * 6)) & 0b111111;
+                    unwrapped.push(self.forward[idx as usize] as char);
+                } else {
+                    unwrapped.push('=');
                 }
+            }
+        }
 
-                // Encode the 24-bit buffer into 4 Base64 characters
-                (0..4)
-                    .map(|i| {
-                        if i < chunk.len() + 1 {
-                            let idx = (buf >> (18 - i * 6)) & 0b111111;
-                            BASE64_ALPHABET.chars().nth(idx as usize).unwrap()
-                        } else {
-                            '='
-                        }
-                    })
-                    .collect::<String>()
-            })
-            .collect()
+        match self.wrap {
+            Some((width, newline)) if width > 0 => self.wrap_lines(&unwrapped, width, newline),
+            _ => unwrapped,
+        }
+    }
+
+    /// Inserts `newline` into `encoded` every `width` characters.
+    fn wrap_lines(&self, encoded: &str, width: usize, newline: Newline) -> String {
+        let mut wrapped = String::with_capacity(encoded.len());
+        for (i, ch) in encoded.chars().enumerate() {
+            if i > 0 && i % width == 0 {
+                wrapped.push_str(newline.as_str());
+            }
+            wrapped.push(ch);
+        }
+        wrapped
     }
 
     /// Decodes a Base64-encoded string into its original plain text form.
@@ -82,7 +294,7 @@ impl Base64 {
     /// # Errors
     ///
     /// - `Base64Error::InvalidCharacter`: Returned when the input contains characters outside
-    ///   the standard Base64 alphabet.
+    ///   the configured Base64 alphabet.
     /// - `Base64Error::Utf8Error`: Returned when the decoded byte sequence cannot be converted
     ///   into a valid UTF-8 string.
     ///
@@ -95,21 +307,59 @@ impl Base64 {
     /// ```
 
     pub fn decode(&self, input: &str) -> Result<String, Base64Error> {
-        let mut bytes: Vec<u8> = Vec::new();
+        let bytes = self.decode_bytes(input)?;
+
+        String::from_utf8(bytes).map_err(Base64Error::Utf8Error)
+    }
+
+    /// Decodes a Base64-encoded string into the raw bytes it represents.
+    ///
+    /// Unlike [`Base64::decode`], this does not attempt a UTF-8 conversion, so it works for
+    /// binary payloads (images, keys, compressed blobs) as well as text.
+    ///
+    /// # Errors
+    ///
+    /// - `Base64Error::InvalidCharacter`: Returned when the input contains characters outside
+    ///   the configured Base64 alphabet.
+    /// - `Base64Error::InvalidLength`: Returned in [strict mode](Base64::strict) when the input
+    ///   isn't canonical Base64 (a stray trailing symbol, or padding outside the final quad).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let base64 = Base64::new();
+    /// let decoded = base64.decode_bytes("SGVsbG8=").unwrap();
+    /// assert_eq!(decoded, b"Hello");
+    /// ```
+    pub fn decode_bytes(&self, input: &str) -> Result<Vec<u8>, Base64Error> {
+        if self.strict {
+            self.validate_strict(input)?;
+        }
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(decoded_length(input));
         let mut buf: u32 = 0;
         let mut padding_count = 0;
+        let mut n = 0;
+
+        for ch in input.chars() {
+            if ch == '\n' || ch == '\r' {
+                continue;
+            }
 
-        for (i, ch) in input.chars().enumerate() {
-            let n = i % 4;
             match ch {
                 '=' => {
                     padding_count += 1;
                 }
                 _ => {
-                    let idx: u32 = BASE64_ALPHABET
-                        .find(ch)
-                        .ok_or(Base64Error::InvalidCharacter)?
-                        as u32;
+                    let value = if ch.is_ascii() {
+                        self.reverse[ch as usize]
+                    } else {
+                        -1
+                    };
+                    if value < 0 {
+                        return Err(Base64Error::InvalidCharacter);
+                    }
+                    let idx = value as u32;
                     buf |= (idx << (18 - n * 6)) & 0xFFFFFF;
                 }
             }
@@ -120,10 +370,42 @@ impl Base64 {
                     bytes.push(byte);
                 });
                 buf = 0;
+                padding_count = 0;
             }
+
+            n = (n + 1) % 4;
         }
 
-        String::from_utf8(bytes).map_err(Base64Error::Utf8Error)
+        Ok(bytes)
+    }
+
+    /// Checks that `input` is canonical Base64: the non-padding symbol count isn't off by one
+    /// quad character, and any `=` padding (at most two) appears only in, and at the end of, the
+    /// final quad.
+    fn validate_strict(&self, input: &str) -> Result<(), Base64Error> {
+        let significant: Vec<char> = input.chars().filter(|&c| c != '\n' && c != '\r').collect();
+        let padding_count = significant.iter().filter(|&&c| c == '=').count();
+        let non_padding_count = significant.len() - padding_count;
+
+        if non_padding_count % 4 == 1 {
+            return Err(Base64Error::InvalidLength);
+        }
+
+        if padding_count > 0 {
+            let first_pad = significant
+                .iter()
+                .position(|&c| c == '=')
+                .expect("padding_count > 0 implies a '=' is present");
+            let pad_followed_by_data = significant[first_pad..].iter().any(|&c| c != '=');
+            let pad_before_final_quad = first_pad < (significant.len() - 1) / 4 * 4;
+            let too_much_padding = first_pad < significant.len().saturating_sub(2);
+
+            if pad_followed_by_data || pad_before_final_quad || too_much_padding {
+                return Err(Base64Error::InvalidLength);
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -134,12 +416,16 @@ pub enum Base64Error {
     Utf8Error(FromUtf8Error),
     /// Error returned when an invalid Base64 character is encountered during decoding.
     InvalidCharacter,
+    /// Error returned in [strict mode](Base64::strict) when the input isn't canonical Base64:
+    /// a stray trailing symbol, or padding outside the final quad.
+    InvalidLength,
 }
 
 impl std::fmt::Display for Base64Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
             Base64Error::InvalidCharacter => write!(f, "Invalid character in input"),
+            Base64Error::InvalidLength => write!(f, "Invalid Base64 length or padding"),
             Base64Error::Utf8Error(ref e) => e.fmt(f),
         }
     }
@@ -147,7 +433,7 @@ impl std::fmt::Display for Base64Error {
 
 #[cfg(test)]
 mod test {
-    use super::Base64;
+    use super::{decoded_length, encoded_length, Base64, CharacterSet, Config, Newline};
 
     #[test]
     fn test_base64_encoder() {
@@ -173,4 +459,103 @@ mod test {
         assert_eq!(base64.decode("Zm9vYmE=").unwrap(), "fooba");
         assert_eq!(base64.decode("Zm9vYmFy").unwrap(), "foobar");
     }
+
+    #[test]
+    fn test_base64_encoder_url_safe() {
+        let base64 = Base64::with_charset(CharacterSet::UrlSafe);
+        // The last 6-bit group of these bytes is 62, one of the two symbols where
+        // standard and URL-safe Base64 diverge (`+` vs `-`).
+        assert_eq!(base64.encode("??>"), "Pz8-".to_string());
+    }
+
+    #[test]
+    fn test_base64_decoder_url_safe() {
+        let base64 = Base64::with_charset(CharacterSet::UrlSafe);
+        assert_eq!(base64.decode("Pz8-").unwrap(), "??>");
+    }
+
+    #[test]
+    fn test_base64_encode_bytes() {
+        let base64 = Base64::new();
+        let bytes: &[u8] = &[0xff, 0xd8, 0xff, 0xe0];
+        assert_eq!(base64.encode_bytes(bytes), "/9j/4A==".to_string());
+    }
+
+    #[test]
+    fn test_base64_decode_bytes() {
+        let base64 = Base64::new();
+        let bytes: &[u8] = &[0xff, 0xd8, 0xff, 0xe0];
+        assert_eq!(base64.decode_bytes("/9j/4A==").unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_base64_encode_wraps_at_width() {
+        let base64 = Base64::with_config(Config::new().wrap(4, Newline::LF));
+        assert_eq!(base64.encode("foobar"), "Zm9v\nYmFy".to_string());
+    }
+
+    #[test]
+    fn test_base64_encode_wraps_with_crlf() {
+        let base64 = Base64::with_config(Config::new().wrap(4, Newline::CRLF));
+        assert_eq!(base64.encode("foobar"), "Zm9v\r\nYmFy".to_string());
+    }
+
+    #[test]
+    fn test_base64_decode_skips_newlines() {
+        let base64 = Base64::new();
+        assert_eq!(base64.decode("Zm9v\r\nYmFy").unwrap(), "foobar");
+        assert_eq!(base64.decode("Zm9v\nYmFy").unwrap(), "foobar");
+    }
+
+    #[test]
+    fn test_encoded_length() {
+        assert_eq!(encoded_length(0), 0);
+        assert_eq!(encoded_length(1), 4);
+        assert_eq!(encoded_length(2), 4);
+        assert_eq!(encoded_length(3), 4);
+        assert_eq!(encoded_length(4), 8);
+        assert_eq!(encoded_length(6), 8);
+    }
+
+    #[test]
+    fn test_decoded_length() {
+        assert_eq!(decoded_length(""), 0);
+        assert_eq!(decoded_length("Zg=="), 1);
+        assert_eq!(decoded_length("Zm8="), 2);
+        assert_eq!(decoded_length("Zm9v"), 3);
+        assert_eq!(decoded_length("Zm9v\r\nYmFy"), 6);
+    }
+
+    #[test]
+    fn test_base64_strict_accepts_canonical_input() {
+        let base64 = Base64::strict();
+        assert_eq!(base64.decode("Zm9vYmFy").unwrap(), "foobar");
+        assert_eq!(base64.decode("Zm9vYmE=").unwrap(), "fooba");
+        assert_eq!(base64.decode("Zm9vYg==").unwrap(), "foob");
+    }
+
+    #[test]
+    fn test_base64_strict_rejects_stray_trailing_symbol() {
+        let base64 = Base64::strict();
+        // 9 non-padding symbols: 9 % 4 == 1, an orphan trailing character.
+        assert!(matches!(
+            base64.decode("Zm9vYmFyQ"),
+            Err(super::Base64Error::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn test_base64_strict_rejects_misplaced_padding() {
+        let base64 = Base64::strict();
+        assert!(matches!(
+            base64.decode("Zm8=Zm8="),
+            Err(super::Base64Error::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn test_base64_lenient_tolerates_what_strict_rejects() {
+        let base64 = Base64::new();
+        assert_eq!(base64.decode("Zm9vYmFyQ").unwrap(), "foobar");
+    }
 }